@@ -12,18 +12,22 @@ use veloren_query_server::{
     server::{Metrics, QueryServer},
 };
 
-const DEFAULT_SERVER_INFO: ServerInfo = ServerInfo {
-    git_hash: ['\0'; 8],
-    players_count: 100,
-    player_cap: 300,
-    battlemode: ServerBattleMode::GlobalPvE,
-};
+fn default_server_info() -> ServerInfo {
+    ServerInfo {
+        git_hash: ['\0'; 8],
+        players_count: 100,
+        player_cap: 300,
+        battlemode: ServerBattleMode::GlobalPvE,
+        server_name: Some("Demo Server".to_string()),
+    }
+}
 
 #[tokio::main]
 async fn main() {
     tracing_subscriber::fmt::init();
     let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 14006);
-    let (_sender, receiver) = watch::channel(DEFAULT_SERVER_INFO);
+    let default_server_info = default_server_info();
+    let (_sender, receiver) = watch::channel(default_server_info.clone());
     let mut server = QueryServer::new(addr, receiver);
     let metrics = Arc::new(RwLock::new(Metrics::default()));
     let metrics2 = Arc::clone(&metrics);
@@ -35,7 +39,12 @@ async fn main() {
 
     println!("Ping = {}ms", ping.as_millis());
     println!("Server info: {info:?}");
-    assert_eq!(info, DEFAULT_SERVER_INFO);
+    // Client and server here are built from the same version, so the
+    // whole struct matches; an older peer on either side would instead
+    // get back a `ServerInfo` with only the fields its negotiated
+    // protocol version defines (see `proto::Response`), rather than an
+    // error.
+    assert_eq!(info, default_server_info);
 
     let start = Instant::now();
 
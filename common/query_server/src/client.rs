@@ -0,0 +1,55 @@
+use std::{
+    io,
+    net::SocketAddr,
+    time::{Duration, Instant},
+};
+
+use tokio::{net::UdpSocket, time::timeout};
+
+use crate::proto::{Request, Response, ServerInfo, CURRENT_VERSION};
+
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug)]
+pub enum ClientError {
+    Io(io::Error),
+    /// The server didn't reply within [`RESPONSE_TIMEOUT`].
+    Timeout,
+    /// The response couldn't be decoded at the version it claimed.
+    Malformed,
+}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> Self { Self::Io(e) }
+}
+
+/// Client for the server-info query protocol.
+pub struct QueryClient {
+    addr: SocketAddr,
+}
+
+impl QueryClient {
+    pub fn new(addr: SocketAddr) -> Self { Self { addr } }
+
+    /// Requests [`ServerInfo`] from the server, advertising
+    /// [`CURRENT_VERSION`]. The server may reply with an older version's
+    /// worth of fields if it predates this crate; any field that version
+    /// doesn't define comes back `None` rather than causing an error.
+    pub async fn server_info(&mut self) -> Result<(ServerInfo, Duration), ClientError> {
+        let socket = UdpSocket::bind("0.0.0.0:0").await?;
+        socket.connect(self.addr).await?;
+
+        let request = Request { version: CURRENT_VERSION };
+        let start = Instant::now();
+        socket.send(&request.encode()).await?;
+
+        let mut buf = [0u8; 512];
+        let len = timeout(RESPONSE_TIMEOUT, socket.recv(&mut buf))
+            .await
+            .map_err(|_| ClientError::Timeout)??;
+        let ping = start.elapsed();
+
+        let response = Response::decode(&buf[..len]).ok_or(ClientError::Malformed)?;
+        Ok((response.info, ping))
+    }
+}
@@ -0,0 +1,199 @@
+//! Wire format for the server-info query protocol.
+//!
+//! This is a tiny request/response protocol: a client sends a [`Request`]
+//! tagged with the highest version it understands, and the server replies
+//! with a [`Response`] self-tagged with the version it actually encoded
+//! `ServerInfo` at (the lower of its own version and the client's). This
+//! lets `ServerInfo` grow new fields over time without a newer server
+//! breaking older clients, or a newer client breaking on an older server:
+//! fields past what the negotiated version defines are simply never
+//! written, rather than written-and-rejected.
+
+/// Wire format version. Bump this whenever [`ServerInfo`] gains a field
+/// that should go out over the wire.
+pub const CURRENT_VERSION: u8 = 2;
+/// The oldest request version a server here will still answer;
+/// [`crate::server::QueryServer::run`] rejects anything older, counting it
+/// towards [`crate::server::Metrics::requests_rejected`].
+pub const MIN_SUPPORTED_VERSION: u8 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServerBattleMode {
+    GlobalPvP,
+    GlobalPvE,
+}
+
+impl ServerBattleMode {
+    fn to_byte(self) -> u8 {
+        match self {
+            Self::GlobalPvP => 0,
+            Self::GlobalPvE => 1,
+        }
+    }
+
+    fn from_byte(b: u8) -> Option<Self> {
+        match b {
+            0 => Some(Self::GlobalPvP),
+            1 => Some(Self::GlobalPvE),
+            _ => None,
+        }
+    }
+}
+
+/// Information about a running server, as returned by
+/// [`crate::client::QueryClient::server_info`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServerInfo {
+    pub git_hash: [char; 8],
+    pub players_count: u16,
+    pub player_cap: u16,
+    pub battlemode: ServerBattleMode,
+    /// Added at version 2. `None` when the negotiated version is below 2,
+    /// whichever end of the connection is older.
+    pub server_name: Option<String>,
+}
+
+impl ServerInfo {
+    /// Appends the fields `version` is known to carry.
+    fn encode_fields(&self, version: u8, buf: &mut Vec<u8>) {
+        for &c in &self.git_hash {
+            buf.push(c as u8);
+        }
+        buf.extend_from_slice(&self.players_count.to_le_bytes());
+        buf.extend_from_slice(&self.player_cap.to_le_bytes());
+        buf.push(self.battlemode.to_byte());
+
+        if version >= 2 {
+            let name = self.server_name.as_deref().unwrap_or("");
+            let name = &name.as_bytes()[..name.len().min(u8::MAX as usize)];
+            buf.push(name.len() as u8);
+            buf.extend_from_slice(name);
+        }
+    }
+
+    /// Reads the fields `version` defines out of `buf`, leaving any
+    /// trailing bytes (fields from a version newer than ours) unread
+    /// rather than erroring on them.
+    fn decode_fields(version: u8, buf: &[u8]) -> Option<Self> {
+        let git_hash_bytes = buf.get(0..8)?;
+        let mut git_hash = ['\0'; 8];
+        for (c, &b) in git_hash.iter_mut().zip(git_hash_bytes) {
+            *c = b as char;
+        }
+        let players_count = u16::from_le_bytes(buf.get(8..10)?.try_into().ok()?);
+        let player_cap = u16::from_le_bytes(buf.get(10..12)?.try_into().ok()?);
+        let battlemode = ServerBattleMode::from_byte(*buf.get(12)?)?;
+
+        let server_name = if version >= 2 {
+            let len = *buf.get(13)? as usize;
+            Some(std::str::from_utf8(buf.get(14..14 + len)?).ok()?.to_string())
+        } else {
+            None
+        };
+
+        Some(Self {
+            git_hash,
+            players_count,
+            player_cap,
+            battlemode,
+            server_name,
+        })
+    }
+}
+
+/// A client's request for server info, tagged with the highest protocol
+/// version it understands.
+pub struct Request {
+    pub version: u8,
+}
+
+impl Request {
+    pub fn encode(&self) -> Vec<u8> { vec![self.version] }
+
+    pub fn decode(buf: &[u8]) -> Option<Self> {
+        buf.first().map(|&version| Self { version })
+    }
+}
+
+/// A server's reply, self-tagged with the wire version it was encoded
+/// with so a client never has to guess which fields are present.
+pub struct Response {
+    pub info: ServerInfo,
+}
+
+impl Response {
+    /// Encodes `self.info` for the given negotiated `version` (the lower
+    /// of the server's own [`CURRENT_VERSION`] and the version the client
+    /// requested).
+    pub fn encode(&self, version: u8) -> Vec<u8> {
+        let mut buf = vec![version];
+        self.info.encode_fields(version, &mut buf);
+        buf
+    }
+
+    pub fn decode(buf: &[u8]) -> Option<Self> {
+        let version = *buf.first()?;
+        let info = ServerInfo::decode_fields(version, &buf[1..])?;
+        Some(Self { info })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_info() -> ServerInfo {
+        ServerInfo {
+            git_hash: ['a', 'b', 'c', '1', '2', '3', '\0', '\0'],
+            players_count: 12,
+            player_cap: 100,
+            battlemode: ServerBattleMode::GlobalPvP,
+            server_name: Some("Test Server".to_string()),
+        }
+    }
+
+    #[test]
+    fn round_trips_at_v1() {
+        let mut info = sample_info();
+        info.server_name = None;
+        let buf = Response { info: info.clone() }.encode(1);
+        let decoded = Response::decode(&buf).unwrap();
+        assert_eq!(decoded.info, info);
+    }
+
+    #[test]
+    fn round_trips_at_v2() {
+        let info = sample_info();
+        let buf = Response { info: info.clone() }.encode(2);
+        let decoded = Response::decode(&buf).unwrap();
+        assert_eq!(decoded.info, info);
+    }
+
+    #[test]
+    fn v2_server_name_dropped_when_encoded_at_v1() {
+        let info = sample_info();
+        let buf = Response { info: info.clone() }.encode(1);
+        let decoded = Response::decode(&buf).unwrap();
+        assert_eq!(decoded.info.server_name, None);
+    }
+
+    #[test]
+    fn decode_rejects_truncated_server_name() {
+        let info = sample_info();
+        let mut buf = Response { info }.encode(2);
+        // Claim a longer server_name than the buffer actually carries.
+        let len_idx = buf.len() - 1 - "Test Server".len();
+        buf[len_idx] = u8::MAX;
+        assert!(Response::decode(&buf).is_none());
+    }
+
+    #[test]
+    fn request_round_trips() {
+        let request = Request {
+            version: CURRENT_VERSION,
+        };
+        let buf = request.encode();
+        let decoded = Request::decode(&buf).unwrap();
+        assert_eq!(decoded.version, request.version);
+    }
+}
@@ -0,0 +1,52 @@
+use std::{io, net::SocketAddr, sync::Arc};
+
+use tokio::{
+    net::UdpSocket,
+    sync::{watch, RwLock},
+};
+
+use crate::proto::{Request, Response, ServerInfo, CURRENT_VERSION, MIN_SUPPORTED_VERSION};
+
+#[derive(Debug, Default)]
+pub struct Metrics {
+    pub requests_served: u64,
+    pub requests_rejected: u64,
+}
+
+/// Server for the server-info query protocol.
+pub struct QueryServer {
+    addr: SocketAddr,
+    info: watch::Receiver<ServerInfo>,
+}
+
+impl QueryServer {
+    pub fn new(addr: SocketAddr, info: watch::Receiver<ServerInfo>) -> Self { Self { addr, info } }
+
+    pub async fn run(&mut self, metrics: Arc<RwLock<Metrics>>) -> io::Result<()> {
+        let socket = UdpSocket::bind(self.addr).await?;
+        let mut buf = [0u8; 64];
+
+        loop {
+            let (len, peer) = socket.recv_from(&mut buf).await?;
+
+            let Some(request) = Request::decode(&buf[..len]) else {
+                metrics.write().await.requests_rejected += 1;
+                continue;
+            };
+
+            if request.version < MIN_SUPPORTED_VERSION {
+                metrics.write().await.requests_rejected += 1;
+                continue;
+            }
+
+            // If the client asked for a version newer than we speak, fall
+            // back to our own (the client is responsible for tolerating
+            // the older reply).
+            let version = request.version.min(CURRENT_VERSION);
+            let response = Response { info: self.info.borrow().clone() };
+            socket.send_to(&response.encode(version), peer).await?;
+
+            metrics.write().await.requests_served += 1;
+        }
+    }
+}
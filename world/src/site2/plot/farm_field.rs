@@ -1,11 +1,57 @@
 use super::*;
 use crate::{ColumnSample, Land};
 use common::terrain::{Block, BlockKind, SpriteKind};
+use common::util::RandomField;
 use rand::prelude::*;
 use strum::{EnumIter, IntoEnumIterator};
 use vek::*;
 use Dir;
 
+/// World-space size, in blocks, of a maturity cluster: tiles within the same
+/// cluster hash to (roughly) the same growth stage so fields ripen in
+/// patches instead of tile-by-tile noise.
+const MATURITY_CLUSTER_SIZE: i32 = 6;
+
+/// World-space size, in blocks, of a vine cluster, used to cap sprawling
+/// crops (e.g. pumpkin) to roughly one fruit per cluster.
+const FRUIT_CLUSTER_SIZE: i32 = 10;
+
+/// Neighbour offsets a vine stem may grow its fruit onto.
+fn fruit_neighbour_offsets() -> [Vec2<i32>; 4] {
+    [
+        Vec2::new(1, 0),
+        Vec2::new(-1, 0),
+        Vec2::new(0, 1),
+        Vec2::new(0, -1),
+    ]
+}
+
+/// Distance, in blocks, from an irrigation channel at which moisture falls
+/// off to zero.
+const MOISTURE_RANGE: i32 = 10;
+/// How close the field's altitude must be to the local water table for its
+/// channels to actually carry water, rather than being dry ditches. Gates
+/// both the literal `Water` block at the channel tile itself and how much
+/// moisture (and so crop density/maturity) a channel grants the tiles
+/// around it.
+const CHANNEL_WATER_DEPTH: f32 = 2.0;
+/// Moisture multiplier applied to a channel's surroundings when it has no
+/// water of its own: the ditch still shapes the ground, but barely
+/// irrigates anything.
+const DRY_CHANNEL_MOISTURE: f32 = 0.25;
+
+/// Number of tiles the entrance path runs inward from the gate.
+const PATH_LENGTH: i32 = 3;
+
+/// A single irrigation channel cut along one of the field's axes.
+struct Channel {
+    /// Fixed coordinate on the perpendicular axis: an x value if the
+    /// channel runs along y, a y value if it runs along x.
+    offset: i32,
+    along_y: bool,
+    width: i32,
+}
+
 #[derive(EnumIter)]
 enum Crop {
     Wildflower,
@@ -108,6 +154,52 @@ impl Crop {
             ],
         }
     }
+
+    /// Ordered growth stages, youngest first, for crops that visibly ripen.
+    ///
+    /// `None` means the crop has no distinct growth stages worth tracking
+    /// (ornamental or single-appearance crops), so callers should fall back
+    /// to [`Self::sprites`] instead.
+    fn growth_stages(&self) -> Option<&[SpriteKind]> {
+        match self {
+            Self::Wheat => Some(&[
+                SpriteKind::Empty,
+                SpriteKind::Empty,
+                SpriteKind::WheatGreen,
+                SpriteKind::WheatYellow,
+            ]),
+            Self::Flax => Some(&[SpriteKind::Empty, SpriteKind::Empty, SpriteKind::Flax]),
+            Self::Corn => Some(&[SpriteKind::Empty, SpriteKind::Empty, SpriteKind::Corn]),
+            Self::Tomato => Some(&[SpriteKind::Empty, SpriteKind::Tomato]),
+            Self::Carrot => Some(&[SpriteKind::Empty, SpriteKind::Empty, SpriteKind::Carrot]),
+            Self::Radish => Some(&[SpriteKind::Empty, SpriteKind::Empty, SpriteKind::Radish]),
+            Self::Turnip => Some(&[SpriteKind::Empty, SpriteKind::Empty, SpriteKind::Turnip]),
+            Self::Cabbage => Some(&[SpriteKind::Empty, SpriteKind::Empty, SpriteKind::Cabbage]),
+            Self::Pumpkin => Some(&[SpriteKind::Empty, SpriteKind::Empty, SpriteKind::Pumpkin]),
+            Self::Sunflower => Some(&[SpriteKind::Empty, SpriteKind::Sunflower]),
+            // Wildflower and Cactus are a mix of ornamental/foliage sprites
+            // rather than a single plant maturing, so they keep the
+            // weighted-random placement instead of a maturity gradient.
+            Self::Wildflower | Self::Cactus => None,
+        }
+    }
+
+    /// For sprawling vine crops, the `(vine, fruit)` sprite pair: a small
+    /// stem sprite grown on the in-row tile, and the heavy produce sprite
+    /// occasionally grown onto a free neighbouring tile instead. `None` for
+    /// crops that render normally, one sprite per tile.
+    fn fruiting(&self) -> Option<(SpriteKind, SpriteKind)> {
+        match self {
+            // No dedicated vine sprite exists yet; reuse the trailing-grass
+            // sprite as a stand-in stem until one is added.
+            Self::Pumpkin => Some((SpriteKind::LongGrass, SpriteKind::Pumpkin)),
+            _ => None,
+        }
+    }
+
+    /// Whether this crop climbs and so wants trellis/stake support at row
+    /// ends.
+    fn climbs(&self) -> bool { matches!(self, Self::Tomato | Self::Flax) }
 }
 
 /// Represents house data generated by the `generate()` method
@@ -119,6 +211,16 @@ pub struct FarmField {
     pub(crate) alt: i32,
     ori: Vec2<f32>,
     is_desert: bool,
+    /// Per-field seed used to derive deterministic, spatially clustered
+    /// randomness (e.g. crop maturity) that's stable across regenerations.
+    seed: u32,
+    /// Irrigation channels cut along the field's axes.
+    channels: Vec<Channel>,
+    /// Axis-aligned direction pointing out of the field through the door.
+    door_dir: Vec2<i32>,
+    /// World position just outside the door, used to site the entrance
+    /// gate on the nearest boundary tile.
+    gate_wpos: Vec2<i32>,
 }
 
 impl FarmField {
@@ -147,14 +249,189 @@ impl FarmField {
                 .unwrap()
         };
 
+        // Deserts get fewer, wider channels: one broad wadi rather than a
+        // network of narrow ditches.
+        let channel_count = if is_desert { 1 } else { rng.gen_range(1..=2) };
+        let channel_width = if is_desert { 3 } else { 1 };
+        let channels = (0..channel_count)
+            .filter_map(|_| {
+                let along_y = rng.gen_bool(0.5);
+                let (min, max) = if along_y {
+                    (bounds.min.x, bounds.max.x)
+                } else {
+                    (bounds.min.y, bounds.max.y)
+                };
+                let margin = channel_width + 2;
+                (max - min > margin * 2).then(|| Channel {
+                    offset: rng.gen_range(min + margin..max - margin),
+                    along_y,
+                    width: channel_width,
+                })
+            })
+            .collect();
+
         Self {
             bounds,
             alt: land.get_alt_approx(site.tile_center_wpos(door_tile + door_dir)) as i32,
             ori: Vec2::new(ori.sin(), ori.cos()),
             crop,
             is_desert,
+            seed: rng.gen(),
+            channels,
+            door_dir,
+            gate_wpos: site.tile_wpos(door_tile + door_dir),
+        }
+    }
+
+    /// A deterministic maturity value in `[0, 1)` for `wpos`, derived from
+    /// the field's seed. Tiles within the same [`MATURITY_CLUSTER_SIZE`]
+    /// patch share a maturity so fields ripen in clusters rather than
+    /// salt-and-pepper noise.
+    fn maturity_at(&self, wpos: Vec2<i32>) -> f32 {
+        let cluster = wpos.map(|e| e.div_euclid(MATURITY_CLUSTER_SIZE));
+        let hash = RandomField::new(self.seed).get(cluster.with_z(0));
+        (hash % 4096) as f32 / 4096.0
+    }
+
+    /// Signed distance from `wpos` to the nearest irrigation channel, in
+    /// blocks, accounting for channel width. Zero or negative means `wpos`
+    /// is inside the channel itself.
+    fn dist_to_channel(&self, wpos: Vec2<i32>) -> i32 {
+        self.channels
+            .iter()
+            .map(|c| {
+                let d = if c.along_y { wpos.x - c.offset } else { wpos.y - c.offset };
+                d.abs() - c.width / 2
+            })
+            .min()
+            .unwrap_or(MOISTURE_RANGE)
+    }
+
+    /// Whether `wpos` lies within an irrigation channel.
+    fn is_channel_at(&self, wpos: Vec2<i32>) -> bool { self.dist_to_channel(wpos) <= 0 }
+
+    /// Moisture in `[0, 1]` at `wpos`: `1.0` inside a channel, falling off
+    /// to `0.0` by [`MOISTURE_RANGE`] blocks away. `has_water` should be
+    /// whether the field's channels are actually near the water table (see
+    /// [`CHANNEL_WATER_DEPTH`]); a dry channel only weakly irrigates.
+    fn moisture_at(&self, wpos: Vec2<i32>, has_water: bool) -> f32 {
+        let base = 1.0 - (self.dist_to_channel(wpos).max(0) as f32 / MOISTURE_RANGE as f32).min(1.0);
+        if has_water { base } else { base * DRY_CHANNEL_MOISTURE }
+    }
+
+    /// Whether `wpos` falls on one of the crop's planted rows.
+    fn is_trench_at(&self, wpos: Vec2<i32>) -> bool {
+        let t = (self.ori * wpos.as_::<f32>()).magnitude();
+        self.crop
+            .row_spacing()
+            .map(|(w, p)| (t / w).fract() <= p)
+            .unwrap_or(false)
+    }
+
+    /// Whether `wpos` is usable field ground: strictly inside the fence
+    /// ring (so it's never a `FenceI`/`FenceL` tile) and on a planted row.
+    fn is_field_ground(&self, wpos: Vec2<i32>) -> bool {
+        wpos.x > self.bounds.min.x
+            && wpos.x < self.bounds.max.x - 1
+            && wpos.y > self.bounds.min.y
+            && wpos.y < self.bounds.max.y - 1
+            && self.is_trench_at(wpos)
+    }
+
+    /// For the cluster containing `wpos`, deterministically picks a stem
+    /// tile, the neighbouring tile it would grow its fruit onto, and
+    /// whether this cluster bears fruit at all (roughly one in six do).
+    fn fruit_cluster(&self, wpos: Vec2<i32>) -> (Vec2<i32>, Vec2<i32>, bool) {
+        let cluster_origin = wpos.map(|e| e.div_euclid(FRUIT_CLUSTER_SIZE) * FRUIT_CLUSTER_SIZE);
+        let hash = RandomField::new(self.seed ^ 0x5EED_F00D).get(cluster_origin.with_z(2));
+
+        let size = FRUIT_CLUSTER_SIZE as u32;
+        let local = Vec2::new((hash % size) as i32, ((hash / size) % size) as i32);
+        let stem = cluster_origin + local;
+        let target = stem + fruit_neighbour_offsets()[(hash / (size * size)) as usize % 4];
+        let has_fruit = hash % 6 == 0;
+
+        (stem, target, has_fruit)
+    }
+
+    /// Whether `wpos` is a neighbouring tile that a nearby stem has grown
+    /// its fruit onto.
+    fn is_fruit_target(&self, wpos: Vec2<i32>) -> bool {
+        fruit_neighbour_offsets().iter().any(|&offset| {
+            let stem_candidate = wpos - offset;
+            let (stem, target, has_fruit) = self.fruit_cluster(stem_candidate);
+            has_fruit
+                && stem == stem_candidate
+                && target == wpos
+                && self.is_field_ground(stem)
+                && self.is_field_ground(wpos)
+        })
+    }
+
+    /// Whether `wpos` sits on the fence ring, regardless of corner-ness.
+    fn is_edge_at(&self, wpos: Vec2<i32>) -> bool {
+        wpos.x == self.bounds.min.x
+            || wpos.y == self.bounds.min.y
+            || wpos.x == self.bounds.max.x - 1
+            || wpos.y == self.bounds.max.y - 1
+    }
+
+    /// Whether `wpos` is a fence corner (`FenceL`) rather than a straight
+    /// run (`FenceI`).
+    fn is_corner_at(&self, wpos: Vec2<i32>) -> bool {
+        (wpos.x == self.bounds.min.x || wpos.x == self.bounds.max.x - 1)
+            && (wpos.y == self.bounds.min.y || wpos.y == self.bounds.max.y - 1)
+    }
+
+    /// The boundary tile nearest the door, never a corner, where the
+    /// entrance gate is placed.
+    fn gate_tile(&self) -> Vec2<i32> {
+        let min = self.bounds.min;
+        let max = self.bounds.max - Vec2::one();
+        // `clamp(lo, hi)` panics if `lo > hi`, which a field narrower than
+        // 3 tiles along this axis would trigger. Fields this small aren't
+        // expected to be generated: debug builds assert against them, and
+        // release builds raise the upper bound to meet the lower one so
+        // `clamp` can't panic, putting the gate on a corner (or past it,
+        // for a one-tile-wide field) instead.
+        debug_assert!(
+            max.x - min.x >= 2 && max.y - min.y >= 2,
+            "farm field too small to fit a gate"
+        );
+        if self.door_dir.x != 0 {
+            Vec2::new(
+                if self.door_dir.x > 0 { max.x } else { min.x },
+                self.gate_wpos
+                    .y
+                    .clamp(min.y + 1, (max.y - 1).max(min.y + 1)),
+            )
+        } else {
+            Vec2::new(
+                self.gate_wpos
+                    .x
+                    .clamp(min.x + 1, (max.x - 1).max(min.x + 1)),
+                if self.door_dir.y > 0 { max.y } else { min.y },
+            )
         }
     }
+
+    /// Whether `wpos` lies on the short dirt path leading inward from the
+    /// gate.
+    fn is_path_at(&self, wpos: Vec2<i32>) -> bool {
+        let gate = self.gate_tile();
+        (1..=PATH_LENGTH).any(|i| gate - self.door_dir * i == wpos)
+    }
+
+    /// Whether `wpos` is an in-row tile right where a planted row meets the
+    /// fence (excluding the gate itself), the spot a climbing crop's
+    /// trellis/stake goes.
+    fn is_row_end(&self, wpos: Vec2<i32>) -> bool {
+        self.is_trench_at(wpos)
+            && fruit_neighbour_offsets().iter().any(|&offset| {
+                let n = wpos + offset;
+                self.is_edge_at(n) && !self.is_corner_at(n) && n != self.gate_tile()
+            })
+    }
 }
 
 impl Structure for FarmField {
@@ -171,12 +448,7 @@ impl Structure for FarmField {
         col: &ColumnSample,
         z_off: i32,
     ) -> Option<Block> {
-        let t = (self.ori * wpos.as_()).magnitude();
-        let is_trench = self
-            .crop
-            .row_spacing()
-            .map(|(w, p)| (t / w).fract() <= p)
-            .unwrap_or(false);
+        let is_trench = self.is_trench_at(wpos);
 
         let hit_min_x_bounds = wpos.x == self.bounds.min.x;
         let hit_min_y_bounds = wpos.y == self.bounds.min.y;
@@ -212,21 +484,69 @@ impl Structure for FarmField {
             Dir::Y
         };
 
+        let is_gate = is_bounds && !is_corner && wpos == self.gate_tile();
+        let is_path = self.is_path_at(wpos);
+        // Whether the field's channels sit close enough to the water table
+        // to actually carry water, rather than being dry ditches.
+        let has_water = self.alt as f32 - col.water_level < CHANNEL_WATER_DEPTH;
+        let moisture = self.moisture_at(wpos, has_water);
+
         if z_off == 0 {
-            Some(Block::new(
-                if self.is_desert {
-                    BlockKind::Sand
-                } else {
-                    BlockKind::Grass
-                },
-                (Lerp::lerp(
-                    col.surface_color,
-                    col.sub_surface_color * 0.5,
-                    is_trench as i32 as f32,
-                ) * 255.0)
-                    .as_(),
-            ))
+            // A path never floods, even where a channel crosses it: the
+            // entrance stays dry and walkable.
+            if !is_path && has_water && self.is_channel_at(wpos) {
+                Some(Block::new(BlockKind::Water, Rgb::zero()))
+            } else {
+                Some(Block::new(
+                    if self.is_desert {
+                        BlockKind::Sand
+                    } else {
+                        // Tilled soil, darkened by how wet and how furrowed
+                        // the tile is, rather than a flat trench/no-trench
+                        // split.
+                        //
+                        // FIXME: this reuses the existing `Earth` block
+                        // kind as a stand-in for a dedicated tilled-soil
+                        // variant, because `BlockKind` lives in
+                        // `common::terrain::block`, which isn't part of
+                        // this checkout and so can't be extended here.
+                        // This is a blocking dependency on that crate, not
+                        // a finished design choice: a `TilledEarth`
+                        // variant needs to land in `common::terrain::block`
+                        // first, and this call site switched over to it,
+                        // before tilled farmland can be told apart from
+                        // plain dirt. `Earth` only carries the
+                        // moisture-driven darkening below as a rough
+                        // approximation in the meantime.
+                        BlockKind::Earth
+                    },
+                    (Lerp::lerp(
+                        col.surface_color,
+                        col.sub_surface_color * 0.5,
+                        if is_path {
+                            0.15
+                        } else {
+                            (is_trench as i32 as f32 * 0.6 + moisture * 0.4).min(1.0)
+                        },
+                    ) * 255.0)
+                        .as_(),
+                ))
+            }
         } else if z_off == 1 && is_bounds {
+            if is_gate {
+                let ori = match ori {
+                    Dir::Y => 2,
+                    Dir::X => 0,
+                    _ => 0,
+                };
+                return Some(
+                    old.into_vacant()
+                        .with_sprite(SpriteKind::FenceGate)
+                        .with_ori(ori)
+                        .unwrap(),
+                );
+            }
+
             let sprite = if is_corner {
                 SpriteKind::FenceL
             } else {
@@ -248,12 +568,65 @@ impl Structure for FarmField {
             };
 
             Some(old.into_vacant().with_sprite(sprite).with_ori(ori).unwrap())
+        } else if z_off == 1 && (is_path || self.is_channel_at(wpos)) {
+            // Keep the entrance path, and any channel tile, clear of
+            // crops and fence furniture. Channels run axis-aligned while
+            // trench bands follow the field's rotation, so a channel
+            // crosses trench bands repeatedly; without this, crop
+            // sprites (and their maturity/fruiting overlays) would get
+            // placed directly on top of the `Water` ground from z_off ==
+            // 0.
+            Some(old.into_vacant())
+        } else if z_off == 1 && self.crop.climbs() && self.is_row_end(wpos) {
+            // Reuse the fence-post mesh as a stake/trellis until a
+            // dedicated asset exists.
+            Some(
+                old.into_vacant()
+                    .with_sprite(SpriteKind::FenceI)
+                    .with_ori(2)
+                    .unwrap(),
+            )
         } else if z_off == 1 && (is_trench || self.crop.row_spacing().is_none()) {
-            self.crop
-                .sprites()
-                .choose_weighted(rng, |(w, _)| *w)
-                .ok()
-                .and_then(|&(_, s)| Some(old.into_vacant().with_sprite(s?)))
+            if let Some(stages) = self.crop.growth_stages() {
+                // Irrigated tiles ripen ahead of the field's base maturity;
+                // dry corners stay at it.
+                let maturity = (self.maturity_at(wpos) + moisture * 0.35).min(0.999);
+                let stage = ((maturity * stages.len() as f32) as usize).min(stages.len() - 1);
+                // Only once a fruiting crop has reached its final growth
+                // stage does it put out the vine/fruit overlay; before
+                // that it shows the same growing-on gradient as any other
+                // crop, so the maturity gradient built for it stays
+                // visible instead of being shadowed entirely.
+                if stage == stages.len() - 1 {
+                    if let Some((vine, fruit)) = self.crop.fruiting() {
+                        let sprite = if self.is_fruit_target(wpos) { fruit } else { vine };
+                        return Some(old.into_vacant().with_sprite(sprite));
+                    }
+                }
+                Some(old.into_vacant().with_sprite(stages[stage]))
+            } else if let Some((vine, fruit)) = self.crop.fruiting() {
+                let sprite = if self.is_fruit_target(wpos) { fruit } else { vine };
+                Some(old.into_vacant().with_sprite(sprite))
+            } else {
+                // Bias sparser crops (those weighted towards `Empty`) away
+                // from bare soil as moisture rises, so growth thickens
+                // around channels instead of staying uniform.
+                let dryness_bias = (1.0 - moisture) * 6.0;
+                self.crop
+                    .sprites()
+                    .iter()
+                    .map(|&(w, s)| {
+                        if matches!(s, None | Some(SpriteKind::Empty)) {
+                            (w + dryness_bias, s)
+                        } else {
+                            (w, s)
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .choose_weighted(rng, |(w, _)| *w)
+                    .ok()
+                    .and_then(|&(_, s)| Some(old.into_vacant().with_sprite(s?)))
+            }
         } else if z_off == 1 && rng.gen_bool(0.001) {
             Some(old.into_vacant().with_sprite(SpriteKind::Scarecrow))
         } else {